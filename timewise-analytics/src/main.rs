@@ -38,6 +38,100 @@ struct RegressionResult {
     r_squared: f64,
     mse: f64,
     predictions: Vec<f64>,
+    /// Erros-padrão de cada coeficiente, na mesma ordem de `LinearCoefficients`:
+    /// `[erro_padrão(slope), erro_padrão(intercept)]`. Quando `n == 2` (0
+    /// graus de liberdade, o mínimo aceito por `fit`), a variância residual
+    /// não é estimável e os valores são `f64::INFINITY` em vez de `NaN`.
+    estimation_errors: Vec<f64>,
+}
+
+impl RegressionResult {
+    /// Estatísticas t de cada coeficiente (coeficiente / erro-padrão), na
+    /// mesma ordem de `estimation_errors`: `[t(slope), t(intercept)]`
+    fn t_statistics(&self) -> Vec<f64> {
+        let values = [self.coefficients.slope, self.coefficients.intercept];
+        values
+            .iter()
+            .zip(self.estimation_errors.iter())
+            .map(|(value, se)| value / se)
+            .collect()
+    }
+
+    /// Intervalos de confiança bicaudais para cada coeficiente, no nível
+    /// informado (ex.: `0.95` para 95%), usando o quantil t de Student com
+    /// `n - 2` graus de liberdade. Retorna `[(lo, hi)]` para slope e intercept,
+    /// na mesma ordem de `estimation_errors`.
+    fn confidence_intervals(&self, level: f64) -> Vec<(f64, f64)> {
+        let n = self.predictions.len();
+        let degrees_of_freedom = (n - 2) as f64;
+
+        // Com 0 graus de liberdade (n == 2) a variância residual é
+        // indefinida; com `df = 0`, `student_t_cdf` vale 1 para todo `t > 0`
+        // e `student_t_quantile` colapsaria para 0. Não há base
+        // estatística para um intervalo finito nesse caso, então ele é
+        // reportado como ilimitado, coerente com o erro-padrão infinito de
+        // `calculate_standard_errors`.
+        if degrees_of_freedom <= 0.0 {
+            return vec![(f64::NEG_INFINITY, f64::INFINITY); self.estimation_errors.len()];
+        }
+
+        let alpha = 1.0 - level;
+        let t_crit = LinearRegression::student_t_quantile(1.0 - alpha / 2.0, degrees_of_freedom);
+
+        let values = [self.coefficients.slope, self.coefficients.intercept];
+        values
+            .iter()
+            .zip(self.estimation_errors.iter())
+            .map(|(value, se)| (value - t_crit * se, value + t_crit * se))
+            .collect()
+    }
+}
+
+/// Estrutura que armazena os resultados de uma regressão linear múltipla
+/// (um intercepto mais um coeficiente por variável exógena)
+#[derive(Debug, Clone)]
+struct MultipleRegressionResult {
+    coefficients: Vec<f64>, // β1..βk, um por variável em `features`
+    intercept: f64,         // β0
+    r_squared: f64,
+    mse: f64,
+    predictions: Vec<f64>,
+    /// MSE a cada época, na ordem em que foram calculados. Vazio quando o
+    /// resultado vem da solução analítica (`fit_multiple`), preenchido por
+    /// `fit_gradient_descent` para permitir inspecionar a convergência.
+    mse_history: Vec<f64>,
+}
+
+/// Previsões e valores reais de uma única janela de um backtest walk-forward
+#[derive(Debug, Clone)]
+struct WindowForecast {
+    window_start: usize,
+    forecasts: Vec<f64>,
+    actuals: Vec<f64>,
+}
+
+/// Resultado agregado de um backtest walk-forward (`rolling_forecast`)
+#[derive(Debug, Clone)]
+struct RollingForecastResult {
+    window_forecasts: Vec<WindowForecast>,
+    mse: f64,
+    mae: f64,
+    mape: f64, // em percentual (0-100)
+}
+
+/// Veredito de `assess_linearity`: o quão bem uma reta descreve a série
+#[derive(Debug, Clone)]
+struct LinearityAssessment {
+    rmse: f64,
+    max_abs_error: f64,
+    /// `true` quando o erro máximo ou o RMSE excedem os limites informados,
+    /// indicando que um termo quadrático (ou outro modelo não-linear) é
+    /// provavelmente necessário.
+    is_nonlinear: bool,
+    /// Presente quando o intercepto ajustado é fortemente negativo frente à
+    /// escala dos dados, um sinal de que a reta extrapola mal fora da janela
+    /// observada.
+    intercept_warning: Option<String>,
 }
 
 /// Implementação da regressão linear
@@ -67,15 +161,417 @@ impl LinearRegression {
         // Calcula métricas
         let r_squared = Self::calculate_r_squared(&y, &predictions);
         let mse = Self::calculate_mse(&y, &predictions);
+        let estimation_errors = Self::calculate_standard_errors(&x, &y, &predictions);
 
         Ok(RegressionResult {
             coefficients,
             r_squared,
             mse,
             predictions,
+            estimation_errors,
+        })
+    }
+
+    /// Calcula os erros-padrão do slope e do intercepto.
+    ///
+    /// A variância residual não enviesada é σ² = RSS / (n − p), com p = 2
+    /// parâmetros estimados. A matriz de variância-covariância dos
+    /// coeficientes é σ²·(XᵀX)⁻¹; para regressão simples (X = [1, x]) suas
+    /// entradas têm forma fechada em função de `Σ(x − x̄)²`.
+    fn calculate_standard_errors(x: &[f64], y: &[f64], predictions: &[f64]) -> Vec<f64> {
+        let n = x.len() as f64;
+        let p = 2.0;
+        let degrees_of_freedom = n - p;
+
+        // `fit` aceita n == 2 (a reta passa exatamente pelos dois pontos, com
+        // 0 graus de liberdade). A variância residual não é estimável nesse
+        // caso — reportamos erro-padrão infinito (incerteza máxima) em vez de
+        // 0/0 = NaN, para que os coeficientes continuem utilizáveis.
+        if degrees_of_freedom <= 0.0 {
+            return vec![f64::INFINITY, f64::INFINITY];
+        }
+
+        let rss: f64 = y
+            .iter()
+            .zip(predictions.iter())
+            .map(|(&yi, &y_pred)| (yi - y_pred).powi(2))
+            .sum();
+        let residual_variance = rss / degrees_of_freedom;
+
+        let mean_x: f64 = x.iter().sum::<f64>() / n;
+        let sum_sq_dev_x: f64 = x.iter().map(|&xi| (xi - mean_x).powi(2)).sum();
+
+        let slope_se = (residual_variance / sum_sq_dev_x).sqrt();
+        let intercept_se =
+            (residual_variance * (1.0 / n + mean_x.powi(2) / sum_sq_dev_x)).sqrt();
+
+        vec![slope_se, intercept_se]
+    }
+
+    /// Logaritmo da função gama, via aproximação de Lanczos (g=7, 9 termos).
+    /// Usado para montar a função beta incompleta regularizada sem estourar
+    /// `f64` em valores grandes de `a`/`b` (evita calcular fatoriais diretos).
+    // Coeficientes citados com a precisão completa da tabela publicada do
+    // método de Lanczos (g=7, 9 termos); mantidos por inteiro para bater com
+    // a fonte em vez de truncados arbitrariamente (aciona
+    // `clippy::excessive_precision`).
+    #[allow(clippy::excessive_precision)]
+    fn log_gamma(x: f64) -> f64 {
+        const G: f64 = 7.0;
+        const COEFFICIENTS: [f64; 9] = [
+            0.99999999999980993,
+            676.5203681218851,
+            -1259.1392167224028,
+            771.32342877765313,
+            -176.61502916214059,
+            12.507343278686905,
+            -0.13857109526572012,
+            9.9843695780195716e-6,
+            1.5056327351493116e-7,
+        ];
+
+        if x < 0.5 {
+            // Fórmula de reflexão de Euler, para manter o argumento >= 0.5
+            (std::f64::consts::PI / (std::f64::consts::PI * x).sin()).ln() - Self::log_gamma(1.0 - x)
+        } else {
+            let x = x - 1.0;
+            let t = x + G + 0.5;
+            let sum = COEFFICIENTS
+                .iter()
+                .skip(1)
+                .enumerate()
+                .fold(COEFFICIENTS[0], |acc, (i, coeff)| acc + coeff / (x + i as f64 + 1.0));
+
+            0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+        }
+    }
+
+    /// Fração contínua de Lentz usada por `incomplete_beta` (algoritmo de
+    /// Numerical Recipes para `I_x(a, b)`).
+    fn beta_continued_fraction(x: f64, a: f64, b: f64) -> f64 {
+        const MAX_ITER: usize = 200;
+        const EPS: f64 = 1e-14;
+        const TINY: f64 = 1e-300;
+
+        let qab = a + b;
+        let qap = a + 1.0;
+        let qam = a - 1.0;
+        let mut c = 1.0;
+        let mut d = 1.0 - qab * x / qap;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        d = 1.0 / d;
+        let mut h = d;
+
+        for m in 1..=MAX_ITER {
+            let m_f = m as f64;
+            let m2 = 2.0 * m_f;
+
+            let even_term = m_f * (b - m_f) * x / ((qam + m2) * (a + m2));
+            d = 1.0 + even_term * d;
+            if d.abs() < TINY {
+                d = TINY;
+            }
+            c = 1.0 + even_term / c;
+            if c.abs() < TINY {
+                c = TINY;
+            }
+            d = 1.0 / d;
+            h *= d * c;
+
+            let odd_term = -(a + m_f) * (qab + m_f) * x / ((a + m2) * (qap + m2));
+            d = 1.0 + odd_term * d;
+            if d.abs() < TINY {
+                d = TINY;
+            }
+            c = 1.0 + odd_term / c;
+            if c.abs() < TINY {
+                c = TINY;
+            }
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+
+            if (delta - 1.0).abs() < EPS {
+                break;
+            }
+        }
+
+        h
+    }
+
+    /// Função beta incompleta regularizada `I_x(a, b)`, via fração contínua.
+    fn incomplete_beta(x: f64, a: f64, b: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        if x >= 1.0 {
+            return 1.0;
+        }
+
+        let ln_beta = Self::log_gamma(a + b) - Self::log_gamma(a) - Self::log_gamma(b);
+        let front = (ln_beta + a * x.ln() + b * (1.0 - x).ln()).exp();
+
+        if x < (a + 1.0) / (a + b + 2.0) {
+            front * Self::beta_continued_fraction(x, a, b) / a
+        } else {
+            1.0 - front * Self::beta_continued_fraction(1.0 - x, b, a) / b
+        }
+    }
+
+    /// CDF da distribuição t de Student com `df` graus de liberdade, via
+    /// relação com a função beta incompleta regularizada:
+    /// `P(T <= t) = 1 - I_x(df/2, 1/2)/2` para `t > 0`, com `x = df/(df + t²)`.
+    fn student_t_cdf(t: f64, df: f64) -> f64 {
+        let x = df / (df + t * t);
+        let regularized = Self::incomplete_beta(x, df / 2.0, 0.5);
+
+        if t > 0.0 {
+            1.0 - 0.5 * regularized
+        } else {
+            0.5 * regularized
+        }
+    }
+
+    /// Quantil da distribuição t de Student com `df` graus de liberdade, via
+    /// bisseção sobre `student_t_cdf`. Diferente de uma expansão assintótica
+    /// (ex.: Cornish-Fisher a partir do quantil normal), permanece precisa
+    /// mesmo em `df` baixo (1, 2, 3...), o regime mais comum ao calcular
+    /// intervalos de confiança para séries curtas.
+    fn student_t_quantile(p: f64, df: f64) -> f64 {
+        if p <= 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        if p >= 1.0 {
+            return f64::INFINITY;
+        }
+        if p < 0.5 {
+            return -Self::student_t_quantile(1.0 - p, df);
+        }
+        if (p - 0.5).abs() < f64::EPSILON {
+            return 0.0;
+        }
+
+        let mut lo = 0.0;
+        let mut hi = 1.0;
+        while Self::student_t_cdf(hi, df) < p {
+            hi *= 2.0;
+        }
+
+        for _ in 0..200 {
+            let mid = 0.5 * (lo + hi);
+            if Self::student_t_cdf(mid, df) < p {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+
+        0.5 * (lo + hi)
+    }
+
+    /// Realiza regressão linear múltipla via equações normais
+    ///
+    /// `features` contém uma coluna por variável exógena (cada `Vec<f64>` tem
+    /// comprimento `n`, o número de observações); `y` é a variável resposta.
+    /// Resolve `(XᵀX)β = Xᵀy` por eliminação de Gauss com pivoteamento parcial,
+    /// onde X é a matriz de projeto com uma coluna inicial de uns.
+    fn fit_multiple(
+        features: &[Vec<f64>],
+        y: &[f64],
+    ) -> Result<MultipleRegressionResult, LinearRegressionError> {
+        if y.is_empty() || features.is_empty() {
+            return Err(LinearRegressionError::EmptyData);
+        }
+
+        let n = y.len();
+        let k = features.len();
+
+        if features.iter().any(|col| col.len() != n) {
+            return Err(LinearRegressionError::InvalidInput);
+        }
+
+        // p = k + 1 parâmetros (intercepto + k coeficientes)
+        if n <= k + 1 {
+            return Err(LinearRegressionError::InsufficientData);
+        }
+
+        // Monta a matriz de projeto X (n linhas, k+1 colunas, coluna 0 = 1)
+        let design: Vec<Vec<f64>> = (0..n)
+            .map(|i| {
+                let mut row = Vec::with_capacity(k + 1);
+                row.push(1.0);
+                row.extend(features.iter().map(|col| col[i]));
+                row
+            })
+            .collect();
+
+        // Forma XᵀX (gram) e Xᵀy
+        let p = k + 1;
+        let mut gram = vec![vec![0.0; p]; p];
+        let mut xty = vec![0.0; p];
+
+        for (i, row) in design.iter().enumerate() {
+            for a in 0..p {
+                xty[a] += row[a] * y[i];
+                for b in 0..p {
+                    gram[a][b] += row[a] * row[b];
+                }
+            }
+        }
+
+        let beta = Self::solve_linear_system(gram, xty)?;
+
+        let intercept = beta[0];
+        let coefficients = beta[1..].to_vec();
+
+        let predictions: Vec<f64> = design
+            .iter()
+            .map(|row| row.iter().zip(beta.iter()).map(|(xi, bi)| xi * bi).sum())
+            .collect();
+
+        let r_squared = Self::calculate_r_squared(y, &predictions);
+        let mse = Self::calculate_mse(y, &predictions);
+
+        Ok(MultipleRegressionResult {
+            coefficients,
+            intercept,
+            r_squared,
+            mse,
+            predictions,
+            mse_history: Vec::new(),
+        })
+    }
+
+    /// Ajusta uma regressão linear múltipla por gradiente descendente em lote,
+    /// como alternativa à solução analítica de `fit_multiple`. Útil quando a
+    /// matriz XᵀX é mal condicionada ou quando se deseja atualizar os pesos de
+    /// forma incremental em séries grandes, sem montá-la.
+    ///
+    /// Os pesos (incluindo o intercepto w0) partem de zero. A cada época,
+    /// percorre todas as amostras acumulando os gradientes
+    /// `grad_0 = Σe_i` e `grad_j = Σe_i·x_ij`, onde `e_i = ŷ_i − y_i`, e então
+    /// atualiza `w ← w − (learning_rate / n)·grad`.
+    fn fit_gradient_descent(
+        features: &[Vec<f64>],
+        y: &[f64],
+        learning_rate: f64,
+        epochs: usize,
+    ) -> Result<MultipleRegressionResult, LinearRegressionError> {
+        if y.is_empty() || features.is_empty() {
+            return Err(LinearRegressionError::EmptyData);
+        }
+
+        let n = y.len();
+        let k = features.len();
+
+        if features.iter().any(|col| col.len() != n) {
+            return Err(LinearRegressionError::InvalidInput);
+        }
+
+        if n <= k + 1 {
+            return Err(LinearRegressionError::InsufficientData);
+        }
+
+        let mut intercept = 0.0;
+        let mut weights = vec![0.0; k];
+        let mut mse_history = Vec::with_capacity(epochs);
+        let n_f = n as f64;
+
+        let predict = |intercept: f64, weights: &[f64], i: usize| -> f64 {
+            intercept
+                + weights
+                    .iter()
+                    .enumerate()
+                    .map(|(j, w)| w * features[j][i])
+                    .sum::<f64>()
+        };
+
+        for _ in 0..epochs {
+            let mut grad_intercept = 0.0;
+            let mut grad_weights = vec![0.0; k];
+
+            for i in 0..n {
+                let error = predict(intercept, &weights, i) - y[i];
+                grad_intercept += error;
+                for (j, grad_j) in grad_weights.iter_mut().enumerate() {
+                    *grad_j += error * features[j][i];
+                }
+            }
+
+            intercept -= (learning_rate / n_f) * grad_intercept;
+            for (w, grad_j) in weights.iter_mut().zip(grad_weights.iter()) {
+                *w -= (learning_rate / n_f) * grad_j;
+            }
+
+            let epoch_predictions: Vec<f64> = (0..n).map(|i| predict(intercept, &weights, i)).collect();
+            mse_history.push(Self::calculate_mse(y, &epoch_predictions));
+        }
+
+        let predictions: Vec<f64> = (0..n).map(|i| predict(intercept, &weights, i)).collect();
+        let r_squared = Self::calculate_r_squared(y, &predictions);
+        let mse = Self::calculate_mse(y, &predictions);
+
+        Ok(MultipleRegressionResult {
+            coefficients: weights,
+            intercept,
+            r_squared,
+            mse,
+            predictions,
+            mse_history,
         })
     }
 
+    /// Resolve o sistema linear `a·x = b` por eliminação de Gauss com
+    /// pivoteamento parcial. Retorna `InvalidInput` quando a matriz é
+    /// singular (pivô nulo dentro de `f64::EPSILON` após a troca de linhas).
+    fn solve_linear_system(
+        mut a: Vec<Vec<f64>>,
+        mut b: Vec<f64>,
+    ) -> Result<Vec<f64>, LinearRegressionError> {
+        let n = b.len();
+
+        for col in 0..n {
+            // Pivoteamento parcial: escolhe a linha com maior valor absoluto na coluna
+            let pivot_row = (col..n)
+                .max_by(|&i, &j| a[i][col].abs().partial_cmp(&a[j][col].abs()).unwrap())
+                .unwrap();
+
+            if a[pivot_row][col].abs() < f64::EPSILON {
+                return Err(LinearRegressionError::InvalidInput);
+            }
+
+            a.swap(col, pivot_row);
+            b.swap(col, pivot_row);
+
+            for row in (col + 1)..n {
+                let factor = a[row][col] / a[col][col];
+
+                // `row` sempre vem depois de `col`, então dividimos `a` em
+                // duas fatias disjuntas para atualizar a linha `row` lendo a
+                // linha pivô `col` sem precisar de um índice manual.
+                let (pivot_part, current_part) = a.split_at_mut(row);
+                let pivot_row = &pivot_part[col];
+                let current_row = &mut current_part[0];
+                for (current, pivot_value) in current_row.iter_mut().zip(pivot_row.iter()).skip(col) {
+                    *current -= factor * pivot_value;
+                }
+
+                b[row] -= factor * b[col];
+            }
+        }
+
+        // Substituição retroativa
+        let mut x = vec![0.0; n];
+        for row in (0..n).rev() {
+            let sum: f64 = (row + 1..n).map(|c| a[row][c] * x[c]).sum();
+            x[row] = (b[row] - sum) / a[row][row];
+        }
+
+        Ok(x)
+    }
+
     /// Calcula os coeficientes da regressão linear (mínimos quadrados)
     fn calculate_coefficients(x: &[f64], y: &[f64]) -> Result<LinearCoefficients, LinearRegressionError> {
         let n = x.len() as f64;
@@ -109,13 +605,139 @@ impl LinearRegression {
             .collect()
     }
 
-    /// Realiza previsões para períodos futuros
-    fn forecast(coefficients: &LinearCoefficients, periods: usize) -> Vec<f64> {
+    /// Realiza previsões para períodos futuros, continuando o índice de tempo
+    /// a partir de `n` (o tamanho da série de treino) em vez de reiniciá-lo em 0.
+    fn forecast(coefficients: &LinearCoefficients, n: usize, periods: usize) -> Vec<f64> {
         (0..periods)
-            .map(|i| coefficients.slope * (i as f64) + coefficients.intercept)
+            .map(|i| coefficients.slope * ((n + i) as f64) + coefficients.intercept)
             .collect()
     }
 
+    /// Realiza uma validação walk-forward (backtest de janela deslizante):
+    /// desliza uma janela de tamanho `window` sobre `data`, em cada passo
+    /// ajusta a regressão só com a janela, prevê `horizon` passos à frente e
+    /// compara com os valores reais retidos. Agrega MSE, MAE e MAPE fora da
+    /// amostra, uma estimativa mais realista do poder preditivo do que o
+    /// R²/MSE dentro da amostra, que sempre favorecem o ajuste.
+    fn rolling_forecast(
+        data: &[f64],
+        window: usize,
+        horizon: usize,
+    ) -> Result<RollingForecastResult, LinearRegressionError> {
+        if data.is_empty() {
+            return Err(LinearRegressionError::EmptyData);
+        }
+
+        if window < 2 || horizon == 0 || data.len() < window + horizon {
+            return Err(LinearRegressionError::InsufficientData);
+        }
+
+        let mut window_forecasts = Vec::new();
+        let mut squared_errors = Vec::new();
+        let mut absolute_errors = Vec::new();
+        let mut percentage_errors = Vec::new();
+
+        let last_start = data.len() - window - horizon;
+        for start in 0..=last_start {
+            let train = &data[start..start + window];
+            let actuals = &data[start + window..start + window + horizon];
+
+            let fit_result = Self::fit(train)?;
+            let forecasts = Self::forecast(&fit_result.coefficients, window, horizon);
+
+            for (&predicted, &actual) in forecasts.iter().zip(actuals.iter()) {
+                let error = predicted - actual;
+                squared_errors.push(error.powi(2));
+                absolute_errors.push(error.abs());
+                if actual.abs() > f64::EPSILON {
+                    percentage_errors.push((error / actual).abs());
+                }
+            }
+
+            window_forecasts.push(WindowForecast {
+                window_start: start,
+                forecasts,
+                actuals: actuals.to_vec(),
+            });
+        }
+
+        let count = squared_errors.len() as f64;
+        let mse = squared_errors.iter().sum::<f64>() / count;
+        let mae = absolute_errors.iter().sum::<f64>() / count;
+        let mape = if percentage_errors.is_empty() {
+            0.0
+        } else {
+            100.0 * percentage_errors.iter().sum::<f64>() / percentage_errors.len() as f64
+        };
+
+        Ok(RollingForecastResult {
+            window_forecasts,
+            mse,
+            mae,
+            mape,
+        })
+    }
+
+    /// Decide se uma série é bem descrita por uma reta ou se um termo
+    /// quadrático é necessário. Reescala os índices de tempo para `[0, 1)`
+    /// por estabilidade numérica, ajusta a melhor reta e reporta o RMSE e o
+    /// erro absoluto máximo dos resíduos. `is_nonlinear` dispara quando o
+    /// erro máximo excede `max_error_tolerance` ou o RMSE excede
+    /// `rmse_threshold`, permitindo distinguir uma tendência genuinamente
+    /// linear de uma curva sem recorrer a um ajuste polinomial completo.
+    fn assess_linearity(
+        data: &[f64],
+        max_error_tolerance: f64,
+        rmse_threshold: f64,
+    ) -> Result<LinearityAssessment, LinearRegressionError> {
+        if data.is_empty() {
+            return Err(LinearRegressionError::EmptyData);
+        }
+
+        if data.len() < 2 {
+            return Err(LinearRegressionError::InsufficientData);
+        }
+
+        let n = data.len() as f64;
+        let x_scaled: Vec<f64> = (0..data.len()).map(|i| i as f64 / n).collect();
+        let y = data.to_vec();
+
+        let coefficients = Self::calculate_coefficients(&x_scaled, &y)?;
+        let predictions = Self::predict_range(&x_scaled, &coefficients);
+
+        let rmse = Self::calculate_mse(&y, &predictions).sqrt();
+        let max_abs_error = y
+            .iter()
+            .zip(predictions.iter())
+            .map(|(&yi, &y_pred)| (yi - y_pred).abs())
+            .fold(0.0, f64::max);
+
+        let is_nonlinear = max_abs_error > max_error_tolerance || rmse > rmse_threshold;
+
+        // Um intercepto "fortemente negativo" é avaliado frente à escala
+        // típica da série, já que um valor absoluto isolado não diz nada sem
+        // contexto (ex.: -5 é enorme para uma série em torno de 1, irrelevante
+        // para uma em torno de 10000).
+        let mean_abs_data: f64 = y.iter().map(|v| v.abs()).sum::<f64>() / n;
+        let intercept_warning = if coefficients.intercept < 0.0
+            && coefficients.intercept.abs() > mean_abs_data
+        {
+            Some(format!(
+                "Intercepto fortemente negativo ({:.4}) frente à escala dos dados (média absoluta {:.4})",
+                coefficients.intercept, mean_abs_data
+            ))
+        } else {
+            None
+        };
+
+        Ok(LinearityAssessment {
+            rmse,
+            max_abs_error,
+            is_nonlinear,
+            intercept_warning,
+        })
+    }
+
     /// Calcula o coeficiente de determinação R²
     fn calculate_r_squared(actual: &[f64], predicted: &[f64]) -> f64 {
         let mean_actual: f64 = actual.iter().sum::<f64>() / actual.len() as f64;
@@ -159,8 +781,20 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Intercepto: {:.2}", result.coefficients.intercept);
     println!("R²: {:.4}", result.r_squared);
     println!("MSE: {:.4}", result.mse);
-    
-    let forecast = LinearRegression::forecast(&result.coefficients, 3);
+    println!(
+        "Erros-padrão (slope, intercepto): ({:.4}, {:.4})",
+        result.estimation_errors[0], result.estimation_errors[1]
+    );
+    println!(
+        "Estatísticas t (slope, intercepto): {:?}",
+        result.t_statistics()
+    );
+    println!(
+        "IC 95% (slope, intercepto): {:?}",
+        result.confidence_intervals(0.95)
+    );
+
+    let forecast = LinearRegression::forecast(&result.coefficients, sales_data.len(), 3);
     println!("\n📈 Previsões para os próximos 3 períodos:");
     for (i, prediction) in forecast.iter().enumerate() {
         println!("Período {}: {:.2}", i + sales_data.len() + 1, prediction);
@@ -180,7 +814,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("R²: {:.4}", result2.r_squared);
     println!("MSE: {:.4}", result2.mse);
     
-    let forecast2 = LinearRegression::forecast(&result2.coefficients, 2);
+    let forecast2 = LinearRegression::forecast(&result2.coefficients, decreasing_data.len(), 2);
     println!("\n📈 Previsões para os próximos 2 períodos:");
     for (i, prediction) in forecast2.iter().enumerate() {
         println!("Período {}: {:.2}", i + decreasing_data.len() + 1, prediction);
@@ -201,10 +835,90 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("R²: {:.4}", result3.r_squared);
     println!("MSE: {:.4}", result3.mse);
     
+    // Exemplo 4: Regressão linear múltipla (receita em função de duas variáveis)
+    println!("\n📊 EXEMPLO 4: Regressão Múltipla (Receita x Marketing x Desconto)");
+    println!("------------------------------------------");
+
+    let marketing_spend = vec![10.0, 12.0, 14.0, 16.0, 18.0, 20.0];
+    let avg_discount = vec![5.0, 3.0, 4.0, 1.0, 2.0, 0.0];
+    let revenue: Vec<f64> = marketing_spend
+        .iter()
+        .zip(avg_discount.iter())
+        .map(|(&m, &d)| 50.0 + 3.0 * m - 2.0 * d)
+        .collect();
+    println!("Gasto com marketing: {:?}", marketing_spend);
+    println!("Desconto médio (%): {:?}", avg_discount);
+    println!("Receita: {:?}", revenue);
+
+    let multiple_result =
+        LinearRegression::fit_multiple(&[marketing_spend, avg_discount], &revenue)?;
+    println!("Intercepto: {:.2}", multiple_result.intercept);
+    println!("Coeficientes: {:?}", multiple_result.coefficients);
+    println!("R²: {:.4}", multiple_result.r_squared);
+    println!("MSE: {:.4}", multiple_result.mse);
+
+    // Exemplo 5: Gradiente descendente como alternativa à solução analítica
+    println!("\n📊 EXEMPLO 5: Gradiente Descendente (Vendas Mensais)");
+    println!("------------------------------------------");
+
+    let time_index: Vec<f64> = (0..sales_data.len()).map(|i| i as f64).collect();
+    let gd_result =
+        LinearRegression::fit_gradient_descent(&[time_index], &sales_data, 0.05, 5000)?;
+    println!("Intercepto: {:.2}", gd_result.intercept);
+    println!("Coeficientes: {:?}", gd_result.coefficients);
+    println!("MSE final: {:.4}", gd_result.mse);
+    println!(
+        "MSE na primeira x última época: {:.4} -> {:.4}",
+        gd_result.mse_history.first().unwrap(),
+        gd_result.mse_history.last().unwrap()
+    );
+
+    // Exemplo 6: Backtest walk-forward (janela deslizante)
+    println!("\n📊 EXEMPLO 6: Backtest Walk-Forward (Vendas Anuais)");
+    println!("------------------------------------------");
+
+    let yearly_sales: Vec<f64> = (0..12).map(|i| 100.0 + 8.0 * i as f64).collect();
+    println!("Dados: {:?}", yearly_sales);
+
+    let rolling_result = LinearRegression::rolling_forecast(&yearly_sales, 6, 2)?;
+    println!("Janelas avaliadas: {}", rolling_result.window_forecasts.len());
+    if let Some(first_window) = rolling_result.window_forecasts.first() {
+        println!(
+            "Primeira janela (início={}): previsto {:?} vs real {:?}",
+            first_window.window_start, first_window.forecasts, first_window.actuals
+        );
+    }
+    println!("MSE fora da amostra: {:.4}", rolling_result.mse);
+    println!("MAE fora da amostra: {:.4}", rolling_result.mae);
+    println!("MAPE fora da amostra: {:.2}%", rolling_result.mape);
+
+    // Exemplo 7: Avaliação de linearidade (reta vs. tendência quadrática)
+    println!("\n📊 EXEMPLO 7: Linear vs. Quadrático");
+    println!("------------------------------------------");
+
+    let linear_assessment = LinearRegression::assess_linearity(&yearly_sales, 1.0, 1.0)?;
+    println!(
+        "Vendas anuais -> RMSE: {:.4}, erro máx.: {:.4}, não-linear? {}",
+        linear_assessment.rmse, linear_assessment.max_abs_error, linear_assessment.is_nonlinear
+    );
+    if let Some(warning) = &linear_assessment.intercept_warning {
+        println!("Aviso: {}", warning);
+    }
+
+    let quadratic_data: Vec<f64> = (0..12).map(|i| (i * i) as f64).collect();
+    println!("Dados quadráticos: {:?}", quadratic_data);
+    let quadratic_assessment = LinearRegression::assess_linearity(&quadratic_data, 1.0, 1.0)?;
+    println!(
+        "Série quadrática -> RMSE: {:.4}, erro máx.: {:.4}, não-linear? {}",
+        quadratic_assessment.rmse,
+        quadratic_assessment.max_abs_error,
+        quadratic_assessment.is_nonlinear
+    );
+
     // Teste de tratamento de erros
     println!("\n⚠️  TESTE DE TRATAMENTO DE ERROS");
     println!("------------------------------------------");
-    
+
     match LinearRegression::fit(&[]) {
         Ok(_) => println!("❌ Erro: deveria ter falhado com dados vazios"),
         Err(e) => println!("✅ Correto: {}", e),
@@ -272,12 +986,54 @@ mod tests {
             slope: 1.0,
             intercept: 10.0,
         };
-        
-        let forecast = LinearRegression::forecast(&coefficients, 3);
-        
-        assert!((forecast[0] - 10.0).abs() < 1e-10);
-        assert!((forecast[1] - 11.0).abs() < 1e-10);
-        assert!((forecast[2] - 12.0).abs() < 1e-10);
+
+        // Série de treino com 5 observações (x = 0..4): a previsão deve
+        // continuar o índice de tempo a partir de n = 5, não reiniciar em 0.
+        let forecast = LinearRegression::forecast(&coefficients, 5, 3);
+
+        assert!((forecast[0] - 15.0).abs() < 1e-10);
+        assert!((forecast[1] - 16.0).abs() < 1e-10);
+        assert!((forecast[2] - 17.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_rolling_forecast_perfect_trend() {
+        // y = 2x + 1, tendência perfeita: erro fora da amostra deve ser ~0
+        let data: Vec<f64> = (0..10).map(|i| 2.0 * i as f64 + 1.0).collect();
+
+        let result = LinearRegression::rolling_forecast(&data, 5, 2).unwrap();
+
+        assert_eq!(result.window_forecasts.len(), data.len() - 5 - 2 + 1);
+        assert!(result.mse < 1e-8);
+        assert!(result.mae < 1e-8);
+        assert!(result.mape < 1e-6);
+    }
+
+    #[test]
+    fn test_rolling_forecast_insufficient_data() {
+        let data = vec![1.0, 2.0, 3.0];
+        let result = LinearRegression::rolling_forecast(&data, 5, 2);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_assess_linearity_on_linear_data() {
+        let data = vec![1.0, 3.0, 5.0, 7.0, 9.0];
+        let assessment = LinearRegression::assess_linearity(&data, 0.1, 0.1).unwrap();
+
+        assert!(assessment.rmse < 1e-8);
+        assert!(assessment.max_abs_error < 1e-8);
+        assert!(!assessment.is_nonlinear);
+        assert!(assessment.intercept_warning.is_none());
+    }
+
+    #[test]
+    fn test_assess_linearity_on_quadratic_data() {
+        // y = x^2: uma reta não descreve bem esses dados
+        let data: Vec<f64> = (0..10).map(|i| (i * i) as f64).collect();
+        let assessment = LinearRegression::assess_linearity(&data, 1.0, 1.0).unwrap();
+
+        assert!(assessment.is_nonlinear);
     }
 
     #[test]
@@ -317,6 +1073,123 @@ mod tests {
         assert!(r2_bad < 1.0);
     }
 
+    #[test]
+    fn test_estimation_errors_perfect_fit() {
+        // Dados lineares perfeitos: a variância residual é nula, então o
+        // erro-padrão de cada coeficiente fica em 0 (sem NaN).
+        let data = vec![1.0, 3.0, 5.0, 7.0, 9.0];
+        let result = LinearRegression::fit(&data).unwrap();
+
+        assert_eq!(result.estimation_errors.len(), 2);
+        assert!(result.estimation_errors[0] < 1e-8);
+        assert!(result.estimation_errors[1] < 1e-8);
+    }
+
+    #[test]
+    fn test_t_statistics_and_confidence_intervals() {
+        let data = vec![100.0, 120.0, 95.0, 140.0, 110.0, 160.0];
+        let result = LinearRegression::fit(&data).unwrap();
+
+        let t_stats = result.t_statistics();
+        assert_eq!(t_stats.len(), 2);
+        assert!((t_stats[0] - result.coefficients.slope / result.estimation_errors[0]).abs() < 1e-10);
+
+        let intervals = result.confidence_intervals(0.95);
+        assert_eq!(intervals.len(), 2);
+        // O valor pontual deve estar dentro do próprio intervalo de confiança
+        assert!(intervals[0].0 <= result.coefficients.slope && result.coefficients.slope <= intervals[0].1);
+        assert!(intervals[1].0 <= result.coefficients.intercept && result.coefficients.intercept <= intervals[1].1);
+    }
+
+    #[test]
+    fn test_estimation_errors_with_two_points_are_infinite_not_nan() {
+        // fit() aceita n == 2 (0 graus de liberdade): a reta passa
+        // exatamente pelos dois pontos, então a variância residual não é
+        // estimável. Deve reportar infinito, nunca NaN.
+        let result = LinearRegression::fit(&[1.0, 3.0]).unwrap();
+
+        assert!(result.estimation_errors[0].is_infinite());
+        assert!(result.estimation_errors[1].is_infinite());
+        assert!(!result.estimation_errors[0].is_nan());
+        assert!(!result.estimation_errors[1].is_nan());
+
+        let t_stats = result.t_statistics();
+        assert_eq!(t_stats, vec![0.0, 0.0]);
+
+        let intervals = result.confidence_intervals(0.95);
+        assert_eq!(
+            intervals,
+            vec![(f64::NEG_INFINITY, f64::INFINITY), (f64::NEG_INFINITY, f64::INFINITY)]
+        );
+    }
+
+    #[test]
+    fn test_student_t_quantile_accurate_at_low_degrees_of_freedom() {
+        // Valores críticos conhecidos de tabela t (p = 0.975, bicaudal 95%).
+        // Uma expansão assintótica (ex.: Cornish-Fisher) erra muito em df
+        // baixo; a inversão via beta incompleta deve ficar a menos de 1%.
+        let known_critical_values = [
+            (1.0, 12.706),
+            (2.0, 4.303),
+            (3.0, 3.182),
+            (5.0, 2.571),
+            (10.0, 2.228),
+            (30.0, 2.042),
+        ];
+
+        for (df, expected) in known_critical_values {
+            let got = LinearRegression::student_t_quantile(0.975, df);
+            let rel_err = (got - expected).abs() / expected;
+            assert!(rel_err < 0.01, "df={df}: got {got}, expected {expected}, rel_err={rel_err}");
+        }
+    }
+
+    #[test]
+    fn test_fit_gradient_descent_converges() {
+        // y = 3 + 2*x1, dados lineares perfeitos
+        let x1 = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        let y: Vec<f64> = x1.iter().map(|&a| 3.0 + 2.0 * a).collect();
+
+        let result = LinearRegression::fit_gradient_descent(&[x1], &y, 0.05, 2000).unwrap();
+
+        assert!((result.intercept - 3.0).abs() < 0.1);
+        assert!((result.coefficients[0] - 2.0).abs() < 0.1);
+        assert_eq!(result.mse_history.len(), 2000);
+        // MSE da última época deve ser menor que o da primeira
+        assert!(result.mse_history.last().unwrap() < result.mse_history.first().unwrap());
+    }
+
+    #[test]
+    fn test_fit_multiple_perfect_fit() {
+        // y = 3 + 2*x1 - 1*x2
+        let x1 = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        // x2 não é combinação linear de x1 e da constante, senão XᵀX seria
+        // singular e os coeficientes não seriam identificáveis
+        let x2 = vec![2.0, 1.0, 4.0, 3.0, 6.0];
+        let y: Vec<f64> = x1
+            .iter()
+            .zip(x2.iter())
+            .map(|(&a, &b)| 3.0 + 2.0 * a - b)
+            .collect();
+
+        let result = LinearRegression::fit_multiple(&[x1, x2], &y).unwrap();
+
+        assert!((result.intercept - 3.0).abs() < 1e-8);
+        assert!((result.coefficients[0] - 2.0).abs() < 1e-8);
+        assert!((result.coefficients[1] - (-1.0)).abs() < 1e-8);
+        assert!((result.r_squared - 1.0).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_fit_multiple_insufficient_data() {
+        let x1 = vec![1.0, 2.0];
+        let x2 = vec![1.0, 2.0];
+        let y = vec![1.0, 2.0];
+
+        let result = LinearRegression::fit_multiple(&[x1, x2], &y);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_mse_calculation() {
         let actual = vec![1.0, 2.0, 3.0];